@@ -16,6 +16,8 @@ use tracing::instrument;
 
 use crate::{Error, Result};
 
+mod supsmu;
+
 /// Multiple seasonal-trend decomposition of a time series.
 ///
 /// This struct handles with the actual decomposition. Calling [`MSTL::fit`]
@@ -30,6 +32,80 @@ pub struct MSTL<'a> {
     periods: &'a mut Vec<usize>,
     /// Parameters for the STL decomposition.
     stl_params: StlParams,
+    /// Box-Cox transform applied to `y` before decomposition, if any.
+    lambda: Option<Lambda>,
+    /// Seasonal loess window per period, if overridden.
+    seasonal_lengths: Option<SeasonalLengths>,
+    /// Number of outer loop iterations, if overridden.
+    iterations: Option<usize>,
+    /// Early-stopping tolerance on the maximum seasonal component change
+    /// between successive iterations, if set.
+    convergence_tolerance: Option<f64>,
+}
+
+/// Seasonal loess window lengths for [`MSTL::seasonal_lengths`].
+///
+/// Either a single length applied to every period, or one length per
+/// period (matching how `feasts`/`forecast::mstl` expose `s.window`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeasonalLengths {
+    /// Apply the same window to every period.
+    Fixed(usize),
+    /// Apply one window per period, in the same order as `periods`.
+    PerPeriod(Vec<usize>),
+}
+
+impl From<usize> for SeasonalLengths {
+    fn from(window: usize) -> Self {
+        Self::Fixed(window)
+    }
+}
+
+impl From<Vec<usize>> for SeasonalLengths {
+    fn from(windows: Vec<usize>) -> Self {
+        Self::PerPeriod(windows)
+    }
+}
+
+impl From<&[usize]> for SeasonalLengths {
+    fn from(windows: &[usize]) -> Self {
+        Self::PerPeriod(windows.to_vec())
+    }
+}
+
+/// The Box-Cox transform parameter used by [`MSTL::lambda`].
+///
+/// Many real-world series (electricity demand, sales) have multiplicative
+/// seasonality whose amplitude grows with the level. Applying a Box-Cox
+/// transform before decomposing, and inverting it afterwards, handles
+/// these series without changing the rest of the MSTL algorithm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Lambda {
+    /// Use a fixed lambda.
+    Fixed(f64),
+    /// Select lambda automatically using Guerrero's method over a grid
+    /// of values in `[-1, 2]`.
+    Auto,
+}
+
+impl From<f64> for Lambda {
+    fn from(lambda: f64) -> Self {
+        Self::Fixed(lambda)
+    }
+}
+
+/// Invert the Box-Cox transform applied by [`MSTL::lambda`]: `(l*z + 1)^(1/l)`
+/// for `l != 0`, or `exp(z)` for `l == 0`.
+///
+/// This should be applied to the recombined `trend + seasonal + residual`
+/// sum, not to the individual components, since only their sum is
+/// meaningful on the transformed scale.
+pub fn inverse_boxcox(z: f64, lambda: f64) -> f64 {
+    if lambda == 0.0 {
+        z.exp()
+    } else {
+        (lambda * z + 1.0).powf(1.0 / lambda)
+    }
 }
 
 impl<'a> MSTL<'a> {
@@ -41,6 +117,10 @@ impl<'a> MSTL<'a> {
             y,
             periods,
             stl_params: stlrs::params(),
+            lambda: None,
+            seasonal_lengths: None,
+            iterations: None,
+            convergence_tolerance: None,
         }
     }
 
@@ -50,22 +130,129 @@ impl<'a> MSTL<'a> {
         self
     }
 
+    /// Apply a Box-Cox transform to `y` before decomposing, storing the
+    /// lambda used on the returned [`MSTLDecomposition`] so that
+    /// [`MSTLDecomposition::combined`] (and downstream forecasting, see
+    /// [`MSTLModel`][crate::MSTLModel]) can invert the transform on the
+    /// recombined trend + seasonal + residual sum.
+    ///
+    /// Pass a fixed `f64`, or [`Lambda::Auto`] to select lambda
+    /// automatically via Guerrero's method over a grid of values in
+    /// `[-1, 2]`.
+    pub fn lambda(mut self, lambda: impl Into<Lambda>) -> Self {
+        self.lambda = Some(lambda.into());
+        self
+    }
+
+    /// Resolve `self.lambda` to a concrete value, running Guerrero's
+    /// method if `Lambda::Auto` was requested.
+    fn resolve_lambda(&self) -> Option<f64> {
+        match self.lambda {
+            None => None,
+            Some(Lambda::Fixed(l)) => Some(l),
+            Some(Lambda::Auto) => Some(guerrero_lambda(
+                self.y,
+                self.periods.first().copied().unwrap_or(2),
+            )),
+        }
+    }
+
+    /// Set the seasonal loess window(s) used for each period, overriding
+    /// the default of `7 + 4*(i+1)` for the `i`th (sorted) period.
+    ///
+    /// Accepts either a single `usize` applied to every period, or a
+    /// `Vec<usize>`/`&[usize]` with one window per period. Windows are
+    /// rounded up to the nearest odd number, as required by loess.
+    pub fn seasonal_lengths(mut self, seasonal_lengths: impl Into<SeasonalLengths>) -> Self {
+        self.seasonal_lengths = Some(seasonal_lengths.into());
+        self
+    }
+
+    /// Set the number of outer loop iterations to run (mirroring
+    /// `MstlParams::iterations` in `stlrs`), overriding the default of `1`
+    /// for a single period or `2` otherwise. Must be at least `1`; `fit`
+    /// will return an error otherwise.
+    ///
+    /// Series with strongly confounded seasonalities may benefit from more
+    /// iterations to let the per-period seasonal estimates settle. See
+    /// also [`MSTL::convergence_tolerance`] to stop early once they have.
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = Some(iterations);
+        self
+    }
+
+    /// Stop iterating early once the maximum absolute change in any
+    /// seasonal component between successive iterations falls below
+    /// `tolerance`, instead of always running the full iteration count.
+    pub fn convergence_tolerance(mut self, tolerance: f64) -> Self {
+        self.convergence_tolerance = Some(tolerance);
+        self
+    }
+
+    /// Apply the Box-Cox transform with the given lambda to `y`, rejecting
+    /// non-positive observations (for which the transform is undefined).
+    fn boxcox(y: &[f64], lambda: f64) -> Result<Vec<f64>> {
+        if y.iter().any(|&v| v <= 0.0) {
+            return Err(Error::MSTL(
+                "Box-Cox transform requires strictly positive observations".to_string(),
+            ));
+        }
+        Ok(y.iter()
+            .map(|&v| {
+                if lambda == 0.0 {
+                    v.ln()
+                } else {
+                    (v.powf(lambda) - 1.0) / lambda
+                }
+            })
+            .collect())
+    }
+
     /// Run the MSTL algorithm, returning the trend, seasonal, and remainder components.
     #[instrument(skip(self), level = "debug")]
     pub fn fit(mut self) -> Result<MSTLDecomposition> {
-        self.process_periods()?;
-        let seasonal_windows: Vec<usize> = self.seasonal_windows();
-        let iterate = if self.periods.len() == 1 { 1 } else { 2 };
+        self.process_periods();
+        let lambda = self.resolve_lambda();
+        let y: Vec<f64> = match lambda {
+            Some(l) => Self::boxcox(self.y, l)?,
+            None => self.y.to_vec(),
+        };
+        if self.iterations == Some(0) {
+            return Err(Error::MSTL(
+                "iterations must be at least 1, got 0".to_string(),
+            ));
+        }
+
+        // Non-seasonal data: fall back to a supersmoother trend instead of
+        // decomposing into per-period seasonal components.
+        if self.periods.is_empty() {
+            let trend = supsmu::supsmu(&y);
+            let residuals = y.iter().zip(trend.iter()).map(|(y, t)| y - t).collect();
+            return Ok(MSTLDecomposition {
+                trend,
+                seasonal: HashMap::new(),
+                residuals,
+                robust_weights: vec![1.0; y.len()],
+                lambda,
+            });
+        }
+
+        let seasonal_windows: Vec<usize> = self.seasonal_windows()?;
+        let iterate = self
+            .iterations
+            .unwrap_or(if self.periods.len() == 1 { 1 } else { 2 });
 
         let mut seasonals: HashMap<usize, Vec<f64>> = self
             .periods
             .iter()
             .copied()
-            .map(|p| (p, vec![0.0; self.y.len()]))
+            .map(|p| (p, vec![0.0; y.len()]))
             .collect();
-        let mut deseas = self.y.to_vec();
+        let mut deseas = y;
         let mut res: Option<StlResult<f64>> = None;
         for i in 0..iterate {
+            let previous =
+                (self.convergence_tolerance.is_some() && i > 0).then(|| seasonals.clone());
             let zipped = self.periods.iter().zip(seasonal_windows.iter());
             for (period, seasonal_window) in zipped {
                 let seas = seasonals.get_mut(period).unwrap();
@@ -89,6 +276,21 @@ impl<'a> MSTL<'a> {
                     .zip(seas.iter())
                     .for_each(|(d, s)| *d -= *s);
             }
+            // Stop early once the seasonal components have settled, rather
+            // than spending further STL fits on an already-converged series.
+            if let (Some(tolerance), Some(previous)) = (self.convergence_tolerance, previous) {
+                let max_change = seasonals
+                    .iter()
+                    .flat_map(|(period, seas)| {
+                        seas.iter()
+                            .zip(previous[period].iter())
+                            .map(|(new, old)| (new - old).abs())
+                    })
+                    .fold(0.0_f64, f64::max);
+                if max_change < tolerance {
+                    break;
+                }
+            }
         }
         let fit = res.ok_or_else(|| Error::MSTL("no STL fit".to_string()))?;
         let trend = fit.trend;
@@ -102,34 +304,49 @@ impl<'a> MSTL<'a> {
             seasonal: seasonals,
             residuals: deseas,
             robust_weights: rw,
+            lambda,
         })
     }
 
-    /// Return the default seasonal windows.
+    /// Return the seasonal windows to use for each (sorted) period.
     ///
-    /// The seasonal window must be odd, and the MSTL paper recommends
-    // TODO: make this configurable.
-    fn seasonal_windows(&self) -> Vec<usize> {
-        (0..self.periods.len()).map(|i| 7 + 4 * (i + 1)).collect()
+    /// Uses [`MSTL::seasonal_lengths`] if set, otherwise falls back to the
+    /// default `7 + 4*(i+1)` formula. Windows are rounded up to the
+    /// nearest odd number, as required by loess. Called after
+    /// [`MSTL::process_periods`] has sorted and filtered `self.periods`,
+    /// so a per-period override must have exactly one entry per period.
+    fn seasonal_windows(&self) -> Result<Vec<usize>> {
+        match &self.seasonal_lengths {
+            None => Ok((0..self.periods.len())
+                .map(|i| to_odd(7 + 4 * (i + 1)))
+                .collect()),
+            Some(SeasonalLengths::Fixed(window)) => Ok(vec![to_odd(*window); self.periods.len()]),
+            Some(SeasonalLengths::PerPeriod(windows)) => {
+                if windows.len() != self.periods.len() {
+                    return Err(Error::MSTL(format!(
+                        "seasonal_lengths has {} window(s) but there are {} period(s)",
+                        windows.len(),
+                        self.periods.len()
+                    )));
+                }
+                Ok(windows.iter().copied().map(to_odd).collect())
+            }
+        }
     }
 
     /// Process the input periods.
     ///
     /// Specifically:
     /// 1. Sort periods in ascending order.
-    /// 2. Ensure periods is non-empty and that all periods are > 1.
-    /// 3. Remove periods greater than half of the time series.
-    fn process_periods(&mut self) -> Result<()> {
+    /// 2. Remove periods that are not greater than 1, or that are greater
+    ///    than half of the time series.
+    ///
+    /// If no periods remain, `fit` treats the data as non-seasonal and
+    /// falls back to a supersmoother trend.
+    fn process_periods(&mut self) {
         // Sort periods in ascending order to minimise seasonal confounding.
         self.periods.sort_unstable();
-        // For now we don't support non-seasonal data.
-        // TODO: write a supersmoother implementation to handle this case.
-        if self.periods.is_empty() || self.periods.first().unwrap_or(&0) <= &1 {
-            return Err(Error::MSTL("non-seasonal data not supported".to_string()));
-        }
-        // Check for and remove periods greater than half of the time series.
-        self.periods.retain(|p| *p <= self.y.len() / 2);
-        Ok(())
+        self.periods.retain(|p| *p > 1 && *p <= self.y.len() / 2);
     }
 }
 
@@ -145,16 +362,29 @@ pub struct MSTLDecomposition {
     residuals: Vec<f64>,
     /// Weights used in the robust fit.
     robust_weights: Vec<f64>,
+    /// Box-Cox lambda applied to `y` before decomposition, if any. Callers
+    /// that recombine `trend + seasonal + residual` into a forecast on the
+    /// original scale should invert this transform on that sum.
+    lambda: Option<f64>,
 }
 
 impl MSTLDecomposition {
     /// Return the trend component.
+    ///
+    /// If [`MSTLDecomposition::lambda`] is `Some`, this is on the
+    /// Box-Cox-transformed scale, not the original scale of `y`. Use
+    /// [`inverse_boxcox`] on the recombined `trend + seasonal + residual`
+    /// sum (not on this component alone) to get back to the original scale.
     pub fn trend(&self) -> &[f64] {
         &self.trend
     }
 
     /// Return the seasonal component for a given period,
     /// or None if the period is not present.
+    ///
+    /// If [`MSTLDecomposition::lambda`] is `Some`, this is on the
+    /// Box-Cox-transformed scale; see the note on
+    /// [`MSTLDecomposition::trend`].
     pub fn seasonal(&self, period: usize) -> Option<&[f64]> {
         self.seasonal.get(&period).map(|v| v.as_slice())
     }
@@ -165,6 +395,10 @@ impl MSTLDecomposition {
     }
 
     /// Return the residuals.
+    ///
+    /// If [`MSTLDecomposition::lambda`] is `Some`, this is on the
+    /// Box-Cox-transformed scale; see the note on
+    /// [`MSTLDecomposition::trend`].
     pub fn residuals(&self) -> &[f64] {
         &self.residuals
     }
@@ -173,6 +407,133 @@ impl MSTLDecomposition {
     pub fn robust_weights(&self) -> &[f64] {
         &self.robust_weights
     }
+
+    /// Return the Box-Cox lambda applied to `y` before decomposition, if any.
+    ///
+    /// When this is `Some`, [`MSTLDecomposition::trend`],
+    /// [`MSTLDecomposition::seasonal`] and [`MSTLDecomposition::residuals`]
+    /// are all on the transformed scale. Callers that recombine them into a
+    /// forecast should sum the components first and only then invert the
+    /// transform on that sum, via [`inverse_boxcox`].
+    pub fn lambda(&self) -> Option<f64> {
+        self.lambda
+    }
+
+    /// Recombine the trend, seasonal and residual components back into the
+    /// original series, inverting the Box-Cox transform (via
+    /// [`inverse_boxcox`]) if [`MSTLDecomposition::lambda`] is `Some`.
+    ///
+    /// This is the recombination downstream forecasting should use: summing
+    /// the (transformed-scale) components first and inverting the sum,
+    /// rather than inverting each component individually.
+    pub fn combined(&self) -> Vec<f64> {
+        (0..self.trend.len())
+            .map(|i| {
+                let z = self.trend[i]
+                    + self.seasonal.values().map(|s| s[i]).sum::<f64>()
+                    + self.residuals[i];
+                match self.lambda {
+                    Some(lambda) => inverse_boxcox(z, lambda),
+                    None => z,
+                }
+            })
+            .collect()
+    }
+
+    /// Return the strength of the trend, as defined in [Forecasting:
+    /// Principles and Practice](https://otexts.com/fpp3/stlfeatures.html):
+    /// `max(0, 1 - Var(residuals) / Var(trend + residuals))`.
+    ///
+    /// Values close to 1 indicate a strong trend; values close to 0
+    /// indicate little to no trend.
+    pub fn trend_strength(&self) -> f64 {
+        strength(&self.residuals, &self.trend)
+    }
+
+    /// Return the strength of the seasonal component for `period`, as
+    /// defined in [Forecasting: Principles and
+    /// Practice](https://otexts.com/fpp3/stlfeatures.html):
+    /// `max(0, 1 - Var(residuals) / Var(seasonal + residuals))`.
+    ///
+    /// Returns `None` if `period` is not present in this decomposition.
+    pub fn seasonal_strength(&self, period: usize) -> Option<f64> {
+        self.seasonal
+            .get(&period)
+            .map(|seasonal| strength(&self.residuals, seasonal))
+    }
+
+    /// Return the seasonal strength (see [`MSTLDecomposition::seasonal_strength`])
+    /// for every period in this decomposition.
+    pub fn seasonal_strengths(&self) -> HashMap<usize, f64> {
+        self.seasonal
+            .keys()
+            .map(|&period| (period, self.seasonal_strength(period).unwrap()))
+            .collect()
+    }
+}
+
+/// Compute `max(0, 1 - Var(residuals) / Var(component + residuals))`, the
+/// FPP strength measure shared by [`MSTLDecomposition::trend_strength`] and
+/// [`MSTLDecomposition::seasonal_strength`].
+fn strength(residuals: &[f64], component: &[f64]) -> f64 {
+    let combined: Vec<f64> = component
+        .iter()
+        .zip(residuals)
+        .map(|(c, r)| c + r)
+        .collect();
+    let denom = variance(&combined);
+    if denom == 0.0 {
+        return 0.0;
+    }
+    (1.0 - variance(residuals) / denom).max(0.0)
+}
+
+fn variance(x: &[f64]) -> f64 {
+    std_dev(x).powi(2)
+}
+
+/// Select a Box-Cox lambda using Guerrero's (1993) method: split `y` into
+/// blocks of `period` observations and choose the lambda in `[-1, 2]` that
+/// minimises the coefficient of variation of the rescaled block statistics
+/// across the grid.
+fn guerrero_lambda(y: &[f64], period: usize) -> f64 {
+    let period = period.max(2);
+    let chunks: Vec<&[f64]> = y.chunks(period).filter(|c| c.len() == period).collect();
+    if chunks.len() < 2 {
+        return 1.0;
+    }
+    let stats: Vec<(f64, f64)> = chunks.iter().map(|c| (mean(c), std_dev(c))).collect();
+    const GRID_STEPS: usize = 30;
+    (0..=GRID_STEPS)
+        .map(|i| -1.0 + i as f64 * (3.0 / GRID_STEPS as f64))
+        .map(|lambda| {
+            let rescaled: Vec<f64> = stats
+                .iter()
+                .map(|&(m, s)| s / m.powf(1.0 - lambda))
+                .collect();
+            (lambda, std_dev(&rescaled) / mean(&rescaled))
+        })
+        .filter(|(_, cv)| cv.is_finite())
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(1.0, |(lambda, _)| lambda)
+}
+
+/// Round a loess window up to the nearest odd number, as required by loess.
+fn to_odd(window: usize) -> usize {
+    if window % 2 == 0 {
+        window + 1
+    } else {
+        window
+    }
+}
+
+fn mean(x: &[f64]) -> f64 {
+    x.iter().sum::<f64>() / x.len() as f64
+}
+
+fn std_dev(x: &[f64]) -> f64 {
+    let m = mean(x);
+    (x.iter().map(|v| (v - m).powi(2)).sum::<f64>() / x.len() as f64).sqrt()
 }
 
 #[cfg(test)]
@@ -245,4 +606,157 @@ mod tests {
             .zip(expected.residuals().iter())
             .for_each(|(a, b)| assert_approx_eq!(a, b, 1e1_f64));
     }
+
+    /// A short series with two periods, so the default iteration count is 2.
+    fn two_period_series() -> (Vec<f64>, Vec<usize>) {
+        let n = 60;
+        let y = (0..n)
+            .map(|i| {
+                let t = i as f64;
+                10.0 + 0.1 * t + 3.0 * (t % 12.0 - 6.0).abs() + (t % 4.0 - 2.0).abs()
+            })
+            .collect();
+        (y, vec![4, 12])
+    }
+
+    #[test]
+    fn huge_convergence_tolerance_stops_after_two_iterations() {
+        let (y, periods) = two_period_series();
+
+        let mut more_iterations = periods.clone();
+        let stopped_early = MSTL::new(&y, &mut more_iterations)
+            .iterations(10)
+            .convergence_tolerance(f64::MAX)
+            .fit()
+            .unwrap();
+
+        let mut two_iterations = periods.clone();
+        let without_tolerance = MSTL::new(&y, &mut two_iterations)
+            .iterations(2)
+            .fit()
+            .unwrap();
+
+        // A tolerance of f64::MAX is satisfied as soon as it's checked (at
+        // the end of the second iteration), so requesting 10 iterations
+        // should produce exactly the same result as requesting 2.
+        stopped_early
+            .trend()
+            .iter()
+            .zip(without_tolerance.trend())
+            .for_each(|(a, b)| assert_approx_eq!(a, b, 1e-9_f64));
+    }
+
+    #[test]
+    fn zero_convergence_tolerance_never_stops_early() {
+        let (y, periods) = two_period_series();
+
+        let mut with_tolerance = periods.clone();
+        let with_tolerance = MSTL::new(&y, &mut with_tolerance)
+            .iterations(3)
+            .convergence_tolerance(0.0)
+            .fit()
+            .unwrap();
+
+        let mut without_tolerance = periods.clone();
+        let without_tolerance = MSTL::new(&y, &mut without_tolerance)
+            .iterations(3)
+            .fit()
+            .unwrap();
+
+        // A tolerance of 0.0 can never be satisfied (the change is an
+        // absolute value, so it's never < 0.0), so this must run the full
+        // 3 iterations, matching the equivalent run with no tolerance set.
+        with_tolerance
+            .trend()
+            .iter()
+            .zip(without_tolerance.trend())
+            .for_each(|(a, b)| assert_approx_eq!(a, b, 1e-9_f64));
+    }
+
+    #[test]
+    fn zero_iterations_is_rejected() {
+        let (y, mut periods) = two_period_series();
+        let err = MSTL::new(&y, &mut periods).iterations(0).fit().unwrap_err();
+        assert!(matches!(err, Error::MSTL(_)));
+    }
+
+    #[test]
+    fn non_seasonal_falls_back_to_supersmoother() {
+        let y: Vec<f64> = (0..50).map(|i| i as f64 * 0.1).collect();
+        let mut periods = vec![];
+        let res = MSTL::new(&y, &mut periods).fit().unwrap();
+        assert!(res.seasonals().is_empty());
+        assert_eq!(res.trend().len(), y.len());
+        assert_eq!(res.residuals().len(), y.len());
+    }
+
+    #[test]
+    fn seasonal_strength_is_high_for_strong_seasonality() {
+        let n = 100;
+        let period = 10;
+        let mut decomp = MSTLDecomposition::default();
+        decomp.trend = vec![0.0; n];
+        decomp.residuals = (0..n)
+            .map(|i| if i % 2 == 0 { 0.01 } else { -0.01 })
+            .collect();
+        decomp
+            .seasonal
+            .insert(period, (0..n).map(|i| 10.0 * (i % period) as f64).collect());
+
+        let strength = decomp.seasonal_strength(period).unwrap();
+        assert!(strength > 0.99, "{strength}");
+        assert_eq!(decomp.seasonal_strength(period + 1), None);
+    }
+
+    #[test]
+    fn strength_is_zero_when_component_plus_residuals_is_constant() {
+        let n = 10;
+        let mut decomp = MSTLDecomposition::default();
+        decomp.trend = vec![1.0; n];
+        decomp.residuals = vec![0.0; n];
+        decomp.seasonal.insert(5, vec![0.0; n]);
+
+        assert_eq!(decomp.trend_strength(), 0.0);
+        assert_eq!(decomp.seasonal_strength(5).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn boxcox_rejects_non_positive() {
+        let y = vec![1.0, 2.0, -1.0, 4.0];
+        let err = MSTL::boxcox(&y, 0.5).unwrap_err();
+        assert!(matches!(err, Error::MSTL(_)));
+
+        let y = vec![1.0, 0.0, 3.0];
+        let err = MSTL::boxcox(&y, 0.5).unwrap_err();
+        assert!(matches!(err, Error::MSTL(_)));
+    }
+
+    #[test]
+    fn boxcox_round_trips() {
+        let y = vec![1.0, 2.0, 3.5, 10.0, 42.0];
+        for lambda in [-0.5, 0.0, 0.5, 1.0, 2.0] {
+            let transformed = MSTL::boxcox(&y, lambda).unwrap();
+            for (&original, &z) in y.iter().zip(transformed.iter()) {
+                assert_approx_eq!(original, inverse_boxcox(z, lambda), 1e-8_f64);
+            }
+        }
+    }
+
+    #[test]
+    fn combined_inverts_boxcox_back_to_original_scale() {
+        let y: Vec<f64> = (0..48)
+            .map(|i| {
+                let t = i as f64;
+                (50.0 + 10.0 * (t / 12.0 * std::f64::consts::TAU).sin() + t * 0.2).max(1.0)
+            })
+            .collect();
+        let mut periods = vec![12];
+        let decomp = MSTL::new(&y, &mut periods).lambda(0.5).fit().unwrap();
+
+        assert!(decomp.lambda().is_some());
+        let combined = decomp.combined();
+        for (&original, &c) in y.iter().zip(combined.iter()) {
+            assert_approx_eq!(original, c, 1e-6_f64);
+        }
+    }
 }